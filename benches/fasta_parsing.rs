@@ -0,0 +1,53 @@
+// Benchmarks comparing the allocating `FastaReader` against the zero-copy
+// `FastaByteReader` on a synthetic multi-record FASTA input.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use gx_sequence_utils_rs::fasta::FastaReader;
+use gx_sequence_utils_rs::fasta_bytes::FastaByteReader;
+
+fn synthetic_fasta(num_records: usize, line_len: usize, lines_per_record: usize) -> Vec<u8> {
+    let mut data = Vec::new();
+    let bases = b"ACGT";
+    for i in 0..num_records {
+        data.extend_from_slice(format!(">record_{i} a synthetic benchmark record\n").as_bytes());
+        for l in 0..lines_per_record {
+            for j in 0..line_len {
+                data.push(bases[(i + l + j) % bases.len()]);
+            }
+            data.push(b'\n');
+        }
+    }
+    data
+}
+
+fn bench_owned_reader(c: &mut Criterion) {
+    let data = synthetic_fasta(2_000, 70, 20);
+    c.bench_function("FastaReader (owned String)", |b| {
+        b.iter(|| {
+            let reader = FastaReader::new(black_box(data.as_slice()));
+            let mut total = 0usize;
+            for record in reader {
+                total += record.unwrap().len();
+            }
+            black_box(total)
+        })
+    });
+}
+
+fn bench_byte_reader(c: &mut Criterion) {
+    let data = synthetic_fasta(2_000, 70, 20);
+    c.bench_function("FastaByteReader (zero-copy)", |b| {
+        b.iter(|| {
+            let mut reader = FastaByteReader::new(black_box(data.as_slice()));
+            let mut total = 0usize;
+            while let Some(record) = reader.next_record() {
+                total += record.unwrap().seq.len();
+            }
+            black_box(total)
+        })
+    });
+}
+
+criterion_group!(benches, bench_owned_reader, bench_byte_reader);
+criterion_main!(benches);
@@ -1,17 +1,89 @@
 use std::env;
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::process;
 
-fn main() {
+use gx_sequence_utils_rs::fasta::{FastaSequence, FastaWriter};
+use gx_sequence_utils_rs::fastq::{FastqReader, FastqRecord};
+
+/// Mean Phred+33 quality score of a FASTQ record's quality string.
+fn mean_quality(record: &FastqRecord) -> f64 {
+    if record.qual.is_empty() {
+        return 0.0;
+    }
+    let total: u32 = record.qual.bytes().map(|b| (b - 33) as u32).sum();
+    total as f64 / record.qual.len() as f64
+}
+
+fn usage(program: &str) -> ! {
+    eprintln!(
+        "Usage: {program} <input.fastq[.gz|.bz2|.xz|.zst]> <output.fasta> \
+         [--min-quality Q] [--min-length L]"
+    );
+    process::exit(1);
+}
+
+fn parse_arg<T: std::str::FromStr>(args: &[String], i: usize, program: &str) -> T {
+    args.get(i)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or_else(|| usage(program))
+}
+
+fn main() -> io::Result<()> {
     let args: Vec<String> = env::args().collect();
+    if args.len() < 3 {
+        usage(&args[0]);
+    }
 
     let input_filename = &args[1];
     let output_filename = &args[2];
+    let mut min_quality: f64 = 0.0;
+    let mut min_length: usize = 0;
+
+    let mut i = 3;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--min-quality" => {
+                min_quality = parse_arg(&args, i + 1, &args[0]);
+                i += 2;
+            }
+            "--min-length" => {
+                min_length = parse_arg(&args, i + 1, &args[0]);
+                i += 2;
+            }
+            _ => usage(&args[0]),
+        }
+    }
+
+    let reader = FastqReader::from_path(input_filename)?;
+    let output = BufWriter::new(File::create(output_filename)?);
+    let mut writer = FastaWriter::with_default_wrap(output);
 
-    let num_reads = 0;
-    let fastq_read: Option<String> = None;
+    let mut num_reads = 0usize;
+    let mut num_filtered = 0usize;
+
+    for record in reader {
+        let record = record?;
+        if record.check().is_err()
+            || record.seq.len() < min_length
+            || mean_quality(&record) < min_quality
+        {
+            num_filtered += 1;
+            continue;
+        }
+
+        let fasta_record =
+            FastaSequence::with_attrs(&record.id, record.desc.as_deref(), &record.seq);
+        writer.write_record(&fasta_record)?;
+        num_reads += 1;
+    }
 
     if num_reads == 0 {
         println!("No valid FASTQ reads could be processed from {input_filename}");
     } else {
         println!("{num_reads} FASTQ reads were converted to FASTA.");
     }
+    println!("{num_filtered} FASTQ reads were filtered out as invalid or below the quality/length thresholds.");
+
+    Ok(())
 }
@@ -0,0 +1,72 @@
+use std::env;
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::process;
+
+use gx_sequence_utils_rs::fasta::{FastaReader, FastaWriter};
+use gx_sequence_utils_rs::fastq::{FastqReader, FastqWriter};
+use gx_sequence_utils_rs::sample::try_reservoir_sample;
+
+fn usage(program: &str) -> ! {
+    eprintln!("Usage: {program} <input> <output> <n> [--seed SEED]");
+    process::exit(1);
+}
+
+/// Guess whether `filename` holds FASTQ rather than FASTA records, looking
+/// past any compression extension.
+fn looks_like_fastq(filename: &str) -> bool {
+    let stem = filename
+        .trim_end_matches(".gz")
+        .trim_end_matches(".bz2")
+        .trim_end_matches(".xz")
+        .trim_end_matches(".zst");
+    stem.ends_with(".fastq") || stem.ends_with(".fq")
+}
+
+fn main() -> io::Result<()> {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 4 {
+        usage(&args[0]);
+    }
+
+    let input_filename = &args[1];
+    let output_filename = &args[2];
+    let n: usize = args[3].parse().unwrap_or_else(|_| usage(&args[0]));
+    let mut seed: u64 = 0;
+
+    let mut i = 4;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--seed" => {
+                seed = args
+                    .get(i + 1)
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or_else(|| usage(&args[0]));
+                i += 2;
+            }
+            _ => usage(&args[0]),
+        }
+    }
+
+    let output = BufWriter::new(File::create(output_filename)?);
+
+    let sampled_count = if looks_like_fastq(input_filename) {
+        let sampled = try_reservoir_sample(FastqReader::from_path(input_filename)?, n, seed)?;
+        let mut writer = FastqWriter::with_default_wrap(output);
+        for record in &sampled {
+            writer.write_record(record)?;
+        }
+        sampled.len()
+    } else {
+        let sampled = try_reservoir_sample(FastaReader::from_path(input_filename)?, n, seed)?;
+        let mut writer = FastaWriter::with_default_wrap(output);
+        for record in &sampled {
+            writer.write_record(record)?;
+        }
+        sampled.len()
+    };
+
+    println!("{sampled_count} of {n} requested records were sampled from {input_filename}");
+
+    Ok(())
+}
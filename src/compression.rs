@@ -0,0 +1,47 @@
+// Transparent gzip/bzip2/xz/zstd decompression for FASTA/FASTQ input.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+
+use bzip2::read::BzDecoder;
+use flate2::read::MultiGzDecoder;
+use xz2::read::XzDecoder;
+use zstd::stream::read::Decoder as ZstdDecoder;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const BZIP2_MAGIC: [u8; 3] = [0x42, 0x5a, 0x68];
+const XZ_MAGIC: [u8; 6] = [0xfd, 0x37, 0x7a, 0x58, 0x5a, 0x00];
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// Wrap `reader` in the decompressor matching its leading magic bytes.
+/// Unrecognized magic bytes are passed through unchanged.
+pub fn decompress<B>(mut reader: B) -> io::Result<Box<dyn BufRead>>
+where
+    B: BufRead + 'static,
+{
+    let mut magic = [0u8; 6];
+    let peeked = reader.fill_buf()?;
+    let n = peeked.len().min(magic.len());
+    magic[..n].copy_from_slice(&peeked[..n]);
+
+    let inner: Box<dyn Read> = if magic.starts_with(&GZIP_MAGIC) {
+        Box::new(MultiGzDecoder::new(reader))
+    } else if magic.starts_with(&BZIP2_MAGIC) {
+        Box::new(BzDecoder::new(reader))
+    } else if magic.starts_with(&XZ_MAGIC) {
+        Box::new(XzDecoder::new(reader))
+    } else if magic.starts_with(&ZSTD_MAGIC) {
+        Box::new(ZstdDecoder::new(reader)?)
+    } else {
+        return Ok(Box::new(reader));
+    };
+
+    Ok(Box::new(BufReader::new(inner)))
+}
+
+/// Open `path` for reading, transparently decompressing gzip, bzip2, xz or
+/// zstd input detected from its leading magic bytes.
+pub fn open_path<P: AsRef<Path>>(path: P) -> io::Result<Box<dyn BufRead>> {
+    decompress(BufReader::new(File::open(path)?))
+}
@@ -5,6 +5,30 @@
 
 use std::fmt;
 use std::io;
+use std::path::Path;
+
+use crate::compression;
+
+/// Default line width used when wrapping sequence output; matches the width
+/// used by most reference FASTA files.
+pub const DEFAULT_WRAP_WIDTH: usize = 60;
+
+/// Write `data` to `writer`, splitting it into lines of at most `width`
+/// bytes each. A `width` of `0` disables wrapping and writes `data` as a
+/// single line. Shared by [`FastaWriter`] and `fastq::FastqWriter` so that
+/// sequence and quality strings wrap identically.
+pub(crate) fn write_wrapped<W: io::Write>(writer: &mut W, data: &[u8], width: usize) -> io::Result<()> {
+    if width == 0 || data.is_empty() {
+        writer.write_all(data)?;
+        writer.write_all(b"\n")?;
+    } else {
+        for chunk in data.chunks(width) {
+            writer.write_all(chunk)?;
+            writer.write_all(b"\n")?;
+        }
+    }
+    Ok(())
+}
 
 /// Trait for FASTA readers.
 pub trait FastaRead {
@@ -33,6 +57,14 @@ where
     }
 }
 
+impl FastaReader<Box<dyn io::BufRead>> {
+    /// Open `path` and create a Fasta reader over it, transparently
+    /// decompressing gzip, bzip2, xz or zstd input.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(FastaReader::new(compression::open_path(path)?))
+    }
+}
+
 impl<B> Iterator for FastaReader<B>
 where
     B: io::BufRead,
@@ -73,10 +105,7 @@ where
         }
 
         if !self.line_cache.starts_with('>') {
-            return Err(io::Error::new(
-                io::ErrorKind::Other,
-                "Expected > at record start.",
-            ));
+            return Err(io::Error::other("Expected > at record start."));
         }
         let mut header_fields = self.line_cache[1..].trim_end().splitn(2, char::is_whitespace);
         record.id = header_fields.next().map(|s| s.to_owned()).unwrap();
@@ -175,3 +204,31 @@ impl fmt::Display for FastaSequence {
     }
 }
 
+/// A FASTA writer with configurable sequence line wrapping.
+pub struct FastaWriter<W: io::Write> {
+    writer: W,
+    wrap_width: usize,
+}
+
+impl<W: io::Write> FastaWriter<W> {
+    /// Create a new Fasta writer wrapping sequence lines at `wrap_width`
+    /// columns (`0` disables wrapping).
+    pub fn new(writer: W, wrap_width: usize) -> Self {
+        FastaWriter { writer, wrap_width }
+    }
+
+    /// Create a new Fasta writer using [`DEFAULT_WRAP_WIDTH`].
+    pub fn with_default_wrap(writer: W) -> Self {
+        FastaWriter::new(writer, DEFAULT_WRAP_WIDTH)
+    }
+
+    /// Write a single FASTA record, wrapping its sequence at `wrap_width`.
+    pub fn write_record(&mut self, record: &FastaSequence) -> io::Result<()> {
+        match &record.desc {
+            Some(desc) => writeln!(self.writer, ">{} {}", record.id, desc)?,
+            None => writeln!(self.writer, ">{}", record.id)?,
+        }
+        write_wrapped(&mut self.writer, record.seq.as_bytes(), self.wrap_width)
+    }
+}
+
@@ -0,0 +1,270 @@
+// A zero-copy alternative to `fasta::FastaReader`: a large chunk of the
+// input is read into an internal `Vec<u8>`, `memchr` locates newline
+// boundaries within it, a line starting with `>` marks a record boundary,
+// and `id`/`seq` are exposed as `&[u8]`
+// slices into that buffer instead of per-record `String` allocations.
+
+use std::io;
+use std::ops::Range;
+
+use memchr::memchr;
+
+use crate::fasta::FastaSequence;
+
+const DEFAULT_BUFFER_SIZE: usize = 64 * 1024;
+
+/// A FASTA record borrowed from a [`FastaByteReader`]'s internal buffer,
+/// valid until the next call to [`FastaByteReader::next_record`].
+#[derive(Debug)]
+pub struct RefRecord<'a> {
+    pub id: &'a [u8],
+    pub desc: Option<&'a [u8]>,
+    pub seq: &'a [u8],
+}
+
+impl RefRecord<'_> {
+    /// Copy this borrowed record into an owned [`FastaSequence`].
+    pub fn to_owned_record(&self) -> FastaSequence {
+        FastaSequence::with_attrs(
+            &String::from_utf8_lossy(self.id),
+            self.desc.map(|d| String::from_utf8_lossy(d)).as_deref(),
+            &String::from_utf8_lossy(self.seq),
+        )
+    }
+}
+
+/// Strip a single trailing `\r`, so CRLF line endings are handled like
+/// `trim_end()` handles them in the line-based reader.
+fn trim_trailing_cr(data: &[u8], end: usize) -> usize {
+    if end > 0 && data[end - 1] == b'\r' {
+        end - 1
+    } else {
+        end
+    }
+}
+
+/// Split a FASTA/FASTQ header line (without its leading `>`/`@`) into an id
+/// and an optional description, on the first byte of ASCII whitespace.
+fn split_header(header: &[u8]) -> (&[u8], Option<&[u8]>) {
+    match header.iter().position(u8::is_ascii_whitespace) {
+        Some(pos) => (&header[..pos], Some(&header[pos + 1..])),
+        None => (header, None),
+    }
+}
+
+/// A zero-copy, `memchr`-driven FASTA reader.
+pub struct FastaByteReader<R> {
+    reader: R,
+    buf: Vec<u8>,
+    filled: usize,       // end of valid data in `buf`
+    cursor: usize,       // next unread byte, absolute index into `buf`
+    record_start: usize, // absolute index where the in-progress record begins
+    eof: bool,
+}
+
+impl<R> FastaByteReader<R>
+where
+    R: io::Read,
+{
+    /// Create a new zero-copy reader with the default buffer size.
+    pub fn new(reader: R) -> Self {
+        Self::with_capacity(reader, DEFAULT_BUFFER_SIZE)
+    }
+
+    /// Create a new zero-copy reader with a given initial buffer size; the
+    /// buffer grows (doubling) if a single record does not fit.
+    pub fn with_capacity(reader: R, capacity: usize) -> Self {
+        FastaByteReader {
+            reader,
+            buf: vec![0u8; capacity.max(1)],
+            filled: 0,
+            cursor: 0,
+            record_start: 0,
+            eof: false,
+        }
+    }
+
+    /// Shift the still-needed partial record to the front of the buffer
+    /// (growing it if full) and read more bytes. Returns the shift amount,
+    /// so callers can rebase any absolute indices they are tracking.
+    fn fill_more(&mut self) -> io::Result<usize> {
+        let shift = self.record_start;
+        if shift > 0 {
+            self.buf.copy_within(shift..self.filled, 0);
+            self.filled -= shift;
+            self.cursor -= shift;
+            self.record_start = 0;
+        }
+        if self.filled == self.buf.len() {
+            let new_len = self.buf.len() * 2;
+            self.buf.resize(new_len, 0);
+        }
+        let n = self.reader.read(&mut self.buf[self.filled..])?;
+        self.filled += n;
+        if n == 0 {
+            self.eof = true;
+        }
+        Ok(shift)
+    }
+
+    /// Read the next record. Returns `Ok(None)` once the input is exhausted.
+    pub fn next_record(&mut self) -> Option<io::Result<RefRecord<'_>>> {
+        match self.read_record() {
+            Ok(Some((header, seq))) => {
+                let (id, desc) = split_header(&self.buf[header]);
+                Some(Ok(RefRecord {
+                    id,
+                    desc,
+                    seq: &self.buf[seq],
+                }))
+            }
+            Ok(None) => None,
+            Err(err) => Some(Err(err)),
+        }
+    }
+
+    fn read_record(&mut self) -> io::Result<Option<(Range<usize>, Range<usize>)>> {
+        if self.cursor >= self.filled && !self.eof {
+            self.fill_more()?;
+        }
+        if self.cursor >= self.filled {
+            return Ok(None);
+        }
+
+        self.record_start = self.cursor;
+        if self.buf[self.cursor] != b'>' {
+            return Err(io::Error::other("Expected > at record start."));
+        }
+        let mut header_start = self.cursor + 1;
+
+        // Locate the end of the header line.
+        let header_line_end = loop {
+            match memchr(b'\n', &self.buf[self.cursor..self.filled]) {
+                Some(nl) => break self.cursor + nl,
+                None if self.eof => break self.filled,
+                None => {
+                    let shift = self.fill_more()?;
+                    header_start -= shift;
+                }
+            }
+        };
+        let mut header_end = trim_trailing_cr(&self.buf, header_line_end).max(header_start);
+        self.cursor = (header_line_end + 1).min(self.filled);
+
+        // Compact the sequence lines that follow, stripping the embedded
+        // line endings so the finished sequence is one contiguous slice.
+        // Every index below is rebased on `shift` whenever a later refill
+        // shifts the buffer, including `header_start`/`header_end`: the
+        // header is part of this same in-progress record, so it moves too.
+        let mut seq_start = self.cursor;
+        let mut write_pos = seq_start;
+        loop {
+            if self.cursor >= self.filled {
+                if self.eof {
+                    break;
+                }
+                let shift = self.fill_more()?;
+                header_start -= shift;
+                header_end -= shift;
+                seq_start -= shift;
+                write_pos -= shift;
+                continue;
+            }
+            if self.buf[self.cursor] == b'>' {
+                break;
+            }
+            let line_end = match memchr(b'\n', &self.buf[self.cursor..self.filled]) {
+                Some(nl) => self.cursor + nl,
+                None if self.eof => self.filled,
+                None => {
+                    let shift = self.fill_more()?;
+                    header_start -= shift;
+                    header_end -= shift;
+                    seq_start -= shift;
+                    write_pos -= shift;
+                    continue;
+                }
+            };
+            let content_end = trim_trailing_cr(&self.buf, line_end).max(self.cursor);
+            if write_pos != self.cursor {
+                self.buf.copy_within(self.cursor..content_end, write_pos);
+            }
+            write_pos += content_end - self.cursor;
+            self.cursor = (line_end + 1).min(self.filled);
+        }
+
+        Ok(Some((header_start..header_end, seq_start..write_pos)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type OwnedRecord = (String, Option<String>, String);
+
+    fn read_all(data: &[u8], capacity: usize) -> Vec<OwnedRecord> {
+        let mut reader = FastaByteReader::with_capacity(data, capacity);
+        let mut records = Vec::new();
+        while let Some(record) = reader.next_record() {
+            let record = record.expect("unexpected read error");
+            records.push((
+                String::from_utf8(record.id.to_vec()).unwrap(),
+                record
+                    .desc
+                    .map(|d| String::from_utf8(d.to_vec()).unwrap()),
+                String::from_utf8(record.seq.to_vec()).unwrap(),
+            ));
+        }
+        records
+    }
+
+    const MULTI_RECORD: &[u8] =
+        b">id1 desc one\nACGT\nACGT\n>id2\nTTTT\n>id3 another desc\nGGGGCCCC\nAAAA\n";
+
+    fn multi_record_expected() -> Vec<OwnedRecord> {
+        vec![
+            ("id1".to_string(), Some("desc one".to_string()), "ACGTACGT".to_string()),
+            ("id2".to_string(), None, "TTTT".to_string()),
+            (
+                "id3".to_string(),
+                Some("another desc".to_string()),
+                "GGGGCCCCAAAA".to_string(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn parses_multi_record_input_at_various_buffer_sizes() {
+        // Buffer sizes smaller than a single line force mid-line refills and
+        // buffer growth; larger ones exercise the common case.
+        for capacity in [1, 2, 4, 8, 16, 64, 1024] {
+            assert_eq!(
+                read_all(MULTI_RECORD, capacity),
+                multi_record_expected(),
+                "capacity = {capacity}"
+            );
+        }
+    }
+
+    #[test]
+    fn strips_crlf_line_endings() {
+        let data = b">id1 desc\r\nACGT\r\nACGT\r\n>id2\r\nTTTT\r\n";
+        let expected = vec![
+            ("id1".to_string(), Some("desc".to_string()), "ACGTACGT".to_string()),
+            ("id2".to_string(), None, "TTTT".to_string()),
+        ];
+        for capacity in [1, 4, 64] {
+            assert_eq!(read_all(data, capacity), expected, "capacity = {capacity}");
+        }
+    }
+
+    #[test]
+    fn header_without_description_has_no_trailing_cr() {
+        let data = b">only_id\r\nACGT\r\n";
+        assert_eq!(
+            read_all(data, 64),
+            vec![("only_id".to_string(), None, "ACGT".to_string())]
+        );
+    }
+}
@@ -0,0 +1,245 @@
+// A FASTQ counterpart to `fasta.rs`, following the same reading conventions,
+// see https://github.com/galaxyproject/sequence_utils/blob/master/galaxy_utils/sequence/fastq.py
+
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+use crate::compression;
+use crate::fasta::{write_wrapped, DEFAULT_WRAP_WIDTH};
+
+/// Trait for FASTQ readers.
+pub trait FastqRead {
+    fn read(&mut self, record: &mut FastqRecord) -> io::Result<()>;
+}
+
+/// A FASTQ reader.
+#[derive(Default, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct FastqReader<B> {
+    reader: B,
+    error_has_occured: bool,
+    line_cache: String, // last line obtained from reader; cleared after each record
+}
+
+impl<B> FastqReader<B>
+where
+    B: io::BufRead,
+{
+    /// Create a new Fastq reader with an object that implements `io::BufRead`.
+    pub fn new(bufreader: B) -> Self {
+        FastqReader {
+            reader: bufreader,
+            error_has_occured: false,
+            line_cache: String::new(),
+        }
+    }
+}
+
+impl FastqReader<Box<dyn io::BufRead>> {
+    /// Open `path` and create a Fastq reader over it, transparently
+    /// decompressing gzip, bzip2, xz or zstd input.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(FastqReader::new(compression::open_path(path)?))
+    }
+}
+
+impl<B> Iterator for FastqReader<B>
+where
+    B: io::BufRead,
+{
+    type Item = io::Result<FastqRecord>;
+
+    fn next(&mut self) -> Option<io::Result<FastqRecord>> {
+        if self.error_has_occured {
+            None
+        } else {
+            let mut record = FastqRecord::new();
+            match self.read(&mut record) {
+                Ok(()) if record.is_empty() => None,
+                Ok(()) => Some(Ok(record)),
+                Err(err) => {
+                    self.error_has_occured = true;
+                    Some(Err(err))
+                }
+            }
+        }
+    }
+}
+
+impl<B> FastqRead for FastqReader<B>
+where
+    B: io::BufRead,
+{
+    /// Read the next FASTQ record.
+    /// An Ok, but empty result indicates that there are no more records in
+    /// the input.
+    fn read(&mut self, record: &mut FastqRecord) -> io::Result<()> {
+        record.clear();
+        if self.line_cache.is_empty() {
+            self.reader.read_line(&mut self.line_cache)?;
+            if self.line_cache.is_empty() {
+                return Ok(());
+            }
+        }
+
+        if !self.line_cache.starts_with('@') {
+            return Err(io::Error::other("Expected @ at record start."));
+        }
+        let mut header_fields = self.line_cache[1..].trim_end().splitn(2, char::is_whitespace);
+        record.id = header_fields.next().map(|s| s.to_owned()).unwrap();
+        record.desc = header_fields.next().map(|s| s.to_owned());
+
+        loop {
+            self.line_cache.clear();
+            self.reader.read_line(&mut self.line_cache)?;
+            if self.line_cache.is_empty() {
+                return Err(io::Error::other(
+                    "Unexpected end of input, expected + separator line.",
+                ));
+            }
+            if self.line_cache.starts_with('+') {
+                break;
+            }
+            record.seq.push_str(self.line_cache.trim_end());
+        }
+
+        while record.qual.len() < record.seq.len() {
+            self.line_cache.clear();
+            self.reader.read_line(&mut self.line_cache)?;
+            if self.line_cache.is_empty() {
+                return Err(io::Error::other(
+                    "Unexpected end of input while reading quality scores.",
+                ));
+            }
+            record.qual.push_str(self.line_cache.trim_end());
+        }
+        self.line_cache.clear();
+
+        Ok(())
+    }
+}
+
+
+/// A FASTQ record.
+#[derive(Default, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct FastqRecord {
+    pub id: String,
+    pub desc: Option<String>,
+    pub seq: String,
+    pub qual: String,
+}
+
+impl FastqRecord {
+    /// Create a new instance.
+    pub fn new() -> Self {
+        FastqRecord {
+            id: String::new(),
+            desc: None,
+            seq: String::new(),
+            qual: String::new(),
+        }
+    }
+
+    /// Create a new `FastqRecord` from given attributes.
+    pub fn with_attrs(id: &str, desc: Option<&str>, seq: &str, qual: &str) -> Self {
+        let desc = desc.map(|desc| desc.to_owned());
+        FastqRecord {
+            id: id.to_owned(),
+            desc,
+            seq: seq.to_owned(),
+            qual: qual.to_owned(),
+        }
+    }
+
+    /// Get the length of the sequence in bases.
+    pub fn len(&self) -> usize {
+        self.seq.len()
+    }
+
+    /// Check if record is empty.
+    pub fn is_empty(&self) -> bool {
+        self.id.is_empty() && self.desc.is_none() && self.seq.is_empty() && self.qual.is_empty()
+    }
+
+    /// Check validity of Fastq record.
+    pub fn check(&self) -> Result<(), &str> {
+        if self.id.is_empty() {
+            return Err("Expecting id for Fastq record.");
+        }
+        if !self.seq.is_ascii() {
+            return Err("Non-ascii character found in sequence.");
+        }
+        if self.seq.len() != self.qual.len() {
+            return Err("Sequence and quality lengths differ.");
+        }
+        if !self.qual.chars().all(|c| c.is_ascii_graphic()) {
+            return Err("Non-printable character found in quality string.");
+        }
+
+        Ok(())
+    }
+
+    /// Clear the record.
+    fn clear(&mut self) {
+        self.id.clear();
+        self.desc = None;
+        self.seq.clear();
+        self.qual.clear();
+    }
+}
+
+impl fmt::Display for FastqRecord {
+    /// Allows for using `FastqRecord` in a given formatter `f`. In general this is for
+    /// creating a `String` representation of a `FastqRecord` and, optionally, writing it to
+    /// a file.
+    ///
+    /// # Errors
+    /// Returns [`std::fmt::Error`](https://doc.rust-lang.org/std/fmt/struct.Error.html)
+    /// if there is an issue formatting to the stream.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        let header = match self.desc.to_owned() {
+            Some(d) => format!("{} {}", self.id.to_owned(), d),
+            None => self.id.to_owned(),
+        };
+        write!(
+            f,
+            "@{}\n{}\n+\n{}\n",
+            header,
+            self.seq.to_owned(),
+            self.qual.to_owned(),
+        )
+    }
+}
+
+/// A FASTQ writer with configurable sequence/quality line wrapping. The
+/// quality string is wrapped at the same line lengths as the sequence so
+/// that the two stay aligned line-for-line.
+pub struct FastqWriter<W: io::Write> {
+    writer: W,
+    wrap_width: usize,
+}
+
+impl<W: io::Write> FastqWriter<W> {
+    /// Create a new Fastq writer wrapping sequence/quality lines at
+    /// `wrap_width` columns (`0` disables wrapping).
+    pub fn new(writer: W, wrap_width: usize) -> Self {
+        FastqWriter { writer, wrap_width }
+    }
+
+    /// Create a new Fastq writer using [`DEFAULT_WRAP_WIDTH`].
+    pub fn with_default_wrap(writer: W) -> Self {
+        FastqWriter::new(writer, DEFAULT_WRAP_WIDTH)
+    }
+
+    /// Write a single FASTQ record, wrapping its sequence and quality lines
+    /// identically so they stay aligned.
+    pub fn write_record(&mut self, record: &FastqRecord) -> io::Result<()> {
+        match &record.desc {
+            Some(desc) => writeln!(self.writer, "@{} {}", record.id, desc)?,
+            None => writeln!(self.writer, "@{}", record.id)?,
+        }
+        write_wrapped(&mut self.writer, record.seq.as_bytes(), self.wrap_width)?;
+        writeln!(self.writer, "+")?;
+        write_wrapped(&mut self.writer, record.qual.as_bytes(), self.wrap_width)
+    }
+}
@@ -0,0 +1,5 @@
+pub mod compression;
+pub mod fasta;
+pub mod fasta_bytes;
+pub mod fastq;
+pub mod sample;
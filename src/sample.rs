@@ -0,0 +1,56 @@
+// Single-pass, constant-memory subsampling of a record stream.
+
+use std::io;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Reservoir-sample exactly `n` items from `iter` in a single pass
+/// (Algorithm R). Returns the whole input if `iter` yields fewer than `n`
+/// items. `seed` makes runs reproducible.
+pub fn reservoir_sample<T, I>(iter: I, n: usize, seed: u64) -> Vec<T>
+where
+    I: Iterator<Item = T>,
+{
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut reservoir: Vec<T> = Vec::with_capacity(n);
+
+    for (i, item) in iter.enumerate() {
+        if i < n {
+            reservoir.push(item);
+        } else {
+            let j = rng.gen_range(0..=i);
+            if j < n {
+                reservoir[j] = item;
+            }
+        }
+    }
+
+    reservoir
+}
+
+/// Like [`reservoir_sample`], but for a fallible iterator such as
+/// `FastaReader`/`FastqReader`: bails with the first `Err` instead of
+/// silently dropping it, without giving up the single-pass, constant-memory
+/// streaming that makes reservoir sampling worthwhile on huge inputs.
+pub fn try_reservoir_sample<T, I>(iter: I, n: usize, seed: u64) -> io::Result<Vec<T>>
+where
+    I: Iterator<Item = io::Result<T>>,
+{
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut reservoir: Vec<T> = Vec::with_capacity(n);
+
+    for (i, item) in iter.enumerate() {
+        let item = item?;
+        if i < n {
+            reservoir.push(item);
+        } else {
+            let j = rng.gen_range(0..=i);
+            if j < n {
+                reservoir[j] = item;
+            }
+        }
+    }
+
+    Ok(reservoir)
+}